@@ -0,0 +1,392 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use super::vectors::{DVec, DVecOps, Vec2, Vec3, VectorError};
+
+// `T` only guarantees Copy + Add + Mul + Sub, so this is how we derive a
+// zero value of `T` without a `Zero`/`num-traits` dependency.
+#[allow(clippy::eq_op)]
+fn zero_of<T: Copy + Sub<Output = T>>(v: T) -> T {
+    v - v
+}
+
+// ---------------------------
+// Dynamic-size, row-major matrix
+// ---------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mat<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Mat<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    /// Panics if `rows` or `cols` is zero, or if `data.len() != rows * cols`.
+    pub fn new(data: Vec<T>, rows: usize, cols: usize) -> Self {
+        assert!(rows > 0 && cols > 0, "Mat rows and cols must be non-zero");
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "data length must equal rows * cols"
+        );
+        Self { data, rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.data[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self.data[row * self.cols + col] = value;
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                data.push(self.get(row, col));
+            }
+        }
+        Self {
+            data,
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    pub fn matmul(&self, other: &Self) -> Result<Self, VectorError> {
+        if self.cols != other.rows {
+            return Err(VectorError::DimensionMismatch);
+        }
+
+        let mut data = Vec::with_capacity(self.rows * other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                let mut sum = zero_of(self.get(0, 0));
+                for k in 0..self.cols {
+                    sum = sum + self.get(row, k) * other.get(k, col);
+                }
+                data.push(sum);
+            }
+        }
+
+        Ok(Self {
+            data,
+            rows: self.rows,
+            cols: other.cols,
+        })
+    }
+}
+
+impl<T> Mat<T>
+where
+    T: DVecOps,
+{
+    pub fn mul_vec(&self, vec: &DVec<T>) -> Result<DVec<T>, VectorError> {
+        if self.cols != vec.len() {
+            return Err(VectorError::DimensionMismatch);
+        }
+
+        let rhs = vec.as_slice();
+        let mut out = Vec::with_capacity(self.rows);
+        for row in 0..self.rows {
+            let mut sum = zero_of(self.get(0, 0));
+            for (col, &val) in rhs.iter().enumerate() {
+                sum = sum + self.get(row, col) * val;
+            }
+            out.push(sum);
+        }
+
+        Ok(DVec::new(out))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Mat<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+macro_rules! impl_mat_identity {
+    ($t:ty) => {
+        impl Mat<$t> {
+            pub fn identity(n: usize) -> Self {
+                let mut data = vec![0 as $t; n * n];
+                for i in 0..n {
+                    data[i * n + i] = 1 as $t;
+                }
+                Self {
+                    data,
+                    rows: n,
+                    cols: n,
+                }
+            }
+        }
+    };
+}
+
+impl_mat_identity!(f32);
+impl_mat_identity!(f64);
+
+// ---------------------------
+// Fixed-size 2x2 matrix
+// ---------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat2<T> {
+    pub m: [[T; 2]; 2],
+}
+
+impl<T> Mat2<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn new(m: [[T; 2]; 2]) -> Self {
+        Self { m }
+    }
+
+    pub fn mul_vec(&self, v: Vec2<T>) -> Vec2<T> {
+        Vec2::new(
+            self.m[0][0] * v.x + self.m[0][1] * v.y,
+            self.m[1][0] * v.x + self.m[1][1] * v.y,
+        )
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn matmul(&self, other: &Self) -> Self {
+        let zero = zero_of(self.m[0][0]);
+        let mut m = [[zero; 2]; 2];
+        for row in 0..2 {
+            for col in 0..2 {
+                let mut sum = zero;
+                for k in 0..2 {
+                    sum = sum + self.m[row][k] * other.m[k][col];
+                }
+                m[row][col] = sum;
+            }
+        }
+        Self { m }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            m: [[self.m[0][0], self.m[1][0]], [self.m[0][1], self.m[1][1]]],
+        }
+    }
+}
+
+impl Mat2<f32> {
+    pub fn identity() -> Self {
+        Self {
+            m: [[1.0, 0.0], [0.0, 1.0]],
+        }
+    }
+}
+
+impl Mat2<f64> {
+    pub fn identity() -> Self {
+        Self {
+            m: [[1.0, 0.0], [0.0, 1.0]],
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Mat2<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.m[row][col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat2<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.m[row][col]
+    }
+}
+
+// ---------------------------
+// Fixed-size 3x3 matrix
+// ---------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3<T> {
+    pub m: [[T; 3]; 3],
+}
+
+impl<T> Mat3<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn new(m: [[T; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    pub fn mul_vec(&self, v: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.m[0][0] * v.x + self.m[0][1] * v.y + self.m[0][2] * v.z,
+            self.m[1][0] * v.x + self.m[1][1] * v.y + self.m[1][2] * v.z,
+            self.m[2][0] * v.x + self.m[2][1] * v.y + self.m[2][2] * v.z,
+        )
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn matmul(&self, other: &Self) -> Self {
+        let zero = zero_of(self.m[0][0]);
+        let mut m = [[zero; 3]; 3];
+        for row in 0..3 {
+            for col in 0..3 {
+                let mut sum = zero;
+                for k in 0..3 {
+                    sum = sum + self.m[row][k] * other.m[k][col];
+                }
+                m[row][col] = sum;
+            }
+        }
+        Self { m }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn transpose(&self) -> Self {
+        let mut m = self.m;
+        for row in 0..3 {
+            for col in 0..3 {
+                m[row][col] = self.m[col][row];
+            }
+        }
+        Self { m }
+    }
+}
+
+impl Mat3<f32> {
+    pub fn identity() -> Self {
+        Self {
+            m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+}
+
+impl Mat3<f64> {
+    pub fn identity() -> Self {
+        Self {
+            m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Mat3<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.m[row][col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat3<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.m[row][col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mat, Mat2, Mat3};
+    use crate::linalg::vectors::{DVec, Vec2, Vec3, VectorError};
+
+    #[test]
+    fn test_mat_mul_vec() {
+        let m = Mat::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let v = DVec::new(vec![1.0, 1.0, 1.0]);
+
+        assert_eq!(m.mul_vec(&v).unwrap(), DVec::new(vec![6.0, 15.0]));
+    }
+
+    #[test]
+    fn test_mat_mul_vec_dimension_mismatch() {
+        let m = Mat::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2);
+        let v = DVec::new(vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(m.mul_vec(&v).unwrap_err(), VectorError::DimensionMismatch);
+    }
+
+    #[test]
+    fn test_matmul() {
+        let a = Mat::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2);
+        let b = Mat::new(vec![5.0, 6.0, 7.0, 8.0], 2, 2);
+
+        let c = a.matmul(&b).unwrap();
+        assert_eq!(c.get(0, 0), 19.0);
+        assert_eq!(c.get(0, 1), 22.0);
+        assert_eq!(c.get(1, 0), 43.0);
+        assert_eq!(c.get(1, 1), 50.0);
+    }
+
+    #[test]
+    fn test_matmul_dimension_mismatch() {
+        let a = Mat::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2);
+        let b = Mat::new(vec![1.0, 2.0, 3.0], 1, 3);
+
+        assert_eq!(a.matmul(&b).unwrap_err(), VectorError::DimensionMismatch);
+    }
+
+    #[test]
+    fn test_transpose_and_identity() {
+        let m = Mat::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let t = m.transpose();
+
+        assert_eq!(t.rows(), 3);
+        assert_eq!(t.cols(), 2);
+        assert_eq!(t.get(2, 1), 6.0);
+
+        let id = Mat::<f64>::identity(3);
+        assert_eq!(id.get(0, 0), 1.0);
+        assert_eq!(id.get(0, 1), 0.0);
+        assert_eq!(id.get(2, 2), 1.0);
+    }
+
+    #[test]
+    fn test_mat2_mul_vec_and_matmul() {
+        let m = Mat2::new([[1.0, 2.0], [3.0, 4.0]]);
+        let v = Vec2::new(1.0, 1.0);
+
+        assert_eq!(m.mul_vec(v), Vec2::new(3.0, 7.0));
+        assert_eq!(m.matmul(&Mat2::<f64>::identity()), m);
+    }
+
+    #[test]
+    fn test_mat3_mul_vec_and_matmul() {
+        let m = Mat3::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(m.mul_vec(v), v);
+        assert_eq!(m.matmul(&Mat3::<f64>::identity()), m);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mat rows and cols must be non-zero")]
+    fn test_mat_new_rejects_zero_rows() {
+        let _ = Mat::<f64>::new(vec![], 0, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mat rows and cols must be non-zero")]
+    fn test_mat_new_rejects_zero_cols() {
+        let _ = Mat::<f64>::new(vec![], 2, 0);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod matrix;
+pub mod vector_n;
+pub mod vectors;
@@ -0,0 +1,155 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::vectors::{Vec2, Vec3};
+
+// ---------------------------
+// Const-generic, stack-allocated N-dimensional vector
+// ---------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector<T, const N: usize> {
+    data: [T; N],
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn new(data: [T; N]) -> Self {
+        Self { data }
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T> + Default,
+{
+    pub fn dot(self, other: Self) -> T {
+        (0..N).fold(T::default(), |acc, i| acc + self.data[i] * other.data[i])
+    }
+}
+
+impl<T, const N: usize> Add for Vector<T, N>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut data = self.data;
+        for (v, rv) in data.iter_mut().zip(rhs.data.iter()) {
+            *v = *v + *rv;
+        }
+        Self { data }
+    }
+}
+
+impl<T, const N: usize> Sub for Vector<T, N>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut data = self.data;
+        for (v, rv) in data.iter_mut().zip(rhs.data.iter()) {
+            *v = *v - *rv;
+        }
+        Self { data }
+    }
+}
+
+impl<T, const N: usize> Mul<T> for Vector<T, N>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        let mut data = self.data;
+        for v in data.iter_mut() {
+            *v = *v * scalar;
+        }
+        Self { data }
+    }
+}
+
+impl<T> From<Vec2<T>> for Vector<T, 2>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    fn from(v: Vec2<T>) -> Self {
+        Self { data: [v.x, v.y] }
+    }
+}
+
+impl<T> From<Vector<T, 2>> for Vec2<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    fn from(v: Vector<T, 2>) -> Self {
+        Vec2::new(v.data[0], v.data[1])
+    }
+}
+
+impl<T> From<Vec3<T>> for Vector<T, 3>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    fn from(v: Vec3<T>) -> Self {
+        Self {
+            data: [v.x, v.y, v.z],
+        }
+    }
+}
+
+impl<T> From<Vector<T, 3>> for Vec3<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    fn from(v: Vector<T, 3>) -> Self {
+        Vec3::new(v.data[0], v.data[1], v.data[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vector;
+    use crate::linalg::vectors::{Vec2, Vec3};
+
+    #[test]
+    fn test_vector_zero_length_dot_does_not_panic() {
+        let x: Vector<f64, 0> = Vector::new([]);
+        let y: Vector<f64, 0> = Vector::new([]);
+
+        assert_eq!(x.dot(y), 0.0);
+    }
+
+    #[test]
+    fn test_vector_dot_and_arithmetic() {
+        let x = Vector::new([1.0, 2.0, 3.0, 4.0]);
+        let y = Vector::new([4.0, 3.0, 2.0, 1.0]);
+
+        assert_eq!(x.dot(y), 4.0 + 6.0 + 6.0 + 4.0);
+        assert_eq!(x + y, Vector::new([5.0, 5.0, 5.0, 5.0]));
+        assert_eq!(x - y, Vector::new([-3.0, -1.0, 1.0, 3.0]));
+        assert_eq!(x * 2.0, Vector::new([2.0, 4.0, 6.0, 8.0]));
+    }
+
+    #[test]
+    fn test_vector_vec2_conversions() {
+        let v2 = Vec2::new(1.0, 2.0);
+        let v: Vector<f64, 2> = v2.into();
+
+        assert_eq!(v, Vector::new([1.0, 2.0]));
+        assert_eq!(Vec2::from(v), v2);
+    }
+
+    #[test]
+    fn test_vector_vec3_conversions() {
+        let v3 = Vec3::new(1.0, 2.0, 3.0);
+        let v: Vector<f64, 3> = v3.into();
+
+        assert_eq!(v, Vector::new([1.0, 2.0, 3.0]));
+        assert_eq!(Vec3::from(v), v3);
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Sub, SubAssign};
 
 // ---------------------------
 // Fixed-size generic 2D vector
@@ -12,8 +12,39 @@ pub struct Vec2<T> {
 #[derive(Debug, PartialEq)]
 pub enum VectorError {
     DimensionMismatch,
+    ZeroNorm,
 }
 
+/// Numeric trait for the floating-point operations (`sqrt`, and the `0`/`1`
+/// constants it needs) that `Copy + Add + Mul + Sub` can't express. Only
+/// implemented for `f32`/`f64` — there is no meaningful `sqrt` over integers.
+pub trait Float: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + PartialEq {
+    fn sqrt(self) -> Self;
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ty) => {
+        impl Float for $t {
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
 impl<T> Vec2<T>
 where
     T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
@@ -70,6 +101,31 @@ where
     }
 }
 
+impl<T> Vec2<T>
+where
+    T: Float,
+{
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+
+    pub fn norm(self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    pub fn normalize(self) -> Self {
+        let n = self.norm();
+        Vec2 {
+            x: self.x / n,
+            y: self.y / n,
+        }
+    }
+
+    pub fn distance(self, other: Self) -> T {
+        (self - other).norm()
+    }
+}
+
 // ---------------------------
 // Fixed-size generic 3D vector
 // ---------------------------
@@ -135,6 +191,162 @@ where
     }
 }
 
+impl<T> Vec3<T>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn cross(self, other: Self) -> Self {
+        Vec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: Float,
+{
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+
+    pub fn norm(self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    pub fn normalize(self) -> Self {
+        let n = self.norm();
+        Vec3 {
+            x: self.x / n,
+            y: self.y / n,
+            z: self.z / n,
+        }
+    }
+
+    pub fn distance(self, other: Self) -> T {
+        (self - other).norm()
+    }
+}
+
+/// Element-wise kernel backing `DVec::dot`/`Add`/`Sub`. The scalar impls
+/// (see `impl_scalar_dvec_ops!`) are a plain `zip`-based fallback for the
+/// built-in integer types; `f32`/`f64` override them below with a
+/// lane-chunked fast path (plus scalar tail loop) that autovectorizes
+/// reliably, since those are the types this hot path is actually exercised
+/// with. `DVecOps` is intentionally implemented only for these primitive
+/// numeric types rather than via a blanket impl over `Copy + Add + Mul +
+/// Sub` — Rust has no stable specialization, so a blanket impl would
+/// conflict with the `f32`/`f64` fast-path overrides below. A custom
+/// numeric type can no longer be used with `DVec` without its own
+/// `impl DVecOps`; this is a narrowing from the pre-SIMD `DVec<T>`,
+/// which accepted any `T: Copy + Add + Mul + Sub`.
+pub trait DVecOps: Copy + Add<Output = Self> + Mul<Output = Self> + Sub<Output = Self> {
+    fn dot(a: &[Self], b: &[Self]) -> Self;
+    fn add_into(a: &[Self], b: &[Self], out: &mut Vec<Self>);
+    fn sub_into(a: &[Self], b: &[Self], out: &mut Vec<Self>);
+}
+
+macro_rules! impl_scalar_dvec_ops {
+    ($t:ty) => {
+        impl DVecOps for $t {
+            fn dot(a: &[Self], b: &[Self]) -> Self {
+                a.iter()
+                    .zip(b)
+                    .map(|(x, y)| *x * *y)
+                    .fold(a[0] - a[0], |acc, val| acc + val)
+            }
+
+            fn add_into(a: &[Self], b: &[Self], out: &mut Vec<Self>) {
+                out.extend(a.iter().zip(b).map(|(x, y)| *x + *y));
+            }
+
+            fn sub_into(a: &[Self], b: &[Self], out: &mut Vec<Self>) {
+                out.extend(a.iter().zip(b).map(|(x, y)| *x - *y));
+            }
+        }
+    };
+}
+
+impl_scalar_dvec_ops!(i8);
+impl_scalar_dvec_ops!(i16);
+impl_scalar_dvec_ops!(i32);
+impl_scalar_dvec_ops!(i64);
+impl_scalar_dvec_ops!(i128);
+impl_scalar_dvec_ops!(isize);
+impl_scalar_dvec_ops!(u8);
+impl_scalar_dvec_ops!(u16);
+impl_scalar_dvec_ops!(u32);
+impl_scalar_dvec_ops!(u64);
+impl_scalar_dvec_ops!(u128);
+impl_scalar_dvec_ops!(usize);
+
+// `std::simd` is nightly-only (`#![feature(portable_simd)]`), and this crate
+// doesn't currently gate on nightly, so the fast path below processes `LANES`
+// elements per iteration through independent accumulators instead of going
+// through `std::simd::Simd` directly. LLVM reliably auto-vectorizes this
+// shape on stable, and the scalar tail loop still handles the remainder.
+macro_rules! impl_simd_dvec_ops {
+    ($t:ty, $lanes:expr) => {
+        impl DVecOps for $t {
+            fn dot(a: &[Self], b: &[Self]) -> Self {
+                const LANES: usize = $lanes;
+                let chunks = a.len() / LANES;
+
+                let mut acc = [0 as $t; LANES];
+                for i in 0..chunks {
+                    let lo = i * LANES;
+                    for lane in 0..LANES {
+                        acc[lane] += a[lo + lane] * b[lo + lane];
+                    }
+                }
+
+                let mut sum: $t = acc.iter().sum();
+                for i in chunks * LANES..a.len() {
+                    sum += a[i] * b[i];
+                }
+                sum
+            }
+
+            fn add_into(a: &[Self], b: &[Self], out: &mut Vec<Self>) {
+                const LANES: usize = $lanes;
+                let chunks = a.len() / LANES;
+
+                out.reserve(a.len());
+                for i in 0..chunks {
+                    let lo = i * LANES;
+                    for lane in 0..LANES {
+                        out.push(a[lo + lane] + b[lo + lane]);
+                    }
+                }
+                for i in chunks * LANES..a.len() {
+                    out.push(a[i] + b[i]);
+                }
+            }
+
+            fn sub_into(a: &[Self], b: &[Self], out: &mut Vec<Self>) {
+                const LANES: usize = $lanes;
+                let chunks = a.len() / LANES;
+
+                out.reserve(a.len());
+                for i in 0..chunks {
+                    let lo = i * LANES;
+                    for lane in 0..LANES {
+                        out.push(a[lo + lane] - b[lo + lane]);
+                    }
+                }
+                for i in chunks * LANES..a.len() {
+                    out.push(a[i] - b[i]);
+                }
+            }
+        }
+    };
+}
+
+impl_simd_dvec_ops!(f32, 8);
+impl_simd_dvec_ops!(f64, 4);
+
 // ---------------------------
 // Dynamic-size generic vector
 // ---------------------------
@@ -143,9 +355,85 @@ pub struct DVec<T> {
     data: Vec<T>,
 }
 
+impl<T> DVec<T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T> FromIterator<T> for DVec<T>
+where
+    T: DVecOps,
+{
+    /// Panics if `iter` yields no items, per [`DVec::new`]'s non-empty invariant.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> Index<usize> for DVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<T> IndexMut<usize> for DVec<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
+impl<T> AddAssign<&DVec<T>> for DVec<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    /// Panics if `self` and `rhs` have different lengths.
+    fn add_assign(&mut self, rhs: &DVec<T>) {
+        assert_eq!(
+            self.data.len(),
+            rhs.data.len(),
+            "DVec length mismatch in add_assign"
+        );
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<T> SubAssign<&DVec<T>> for DVec<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    /// Panics if `self` and `rhs` have different lengths.
+    fn sub_assign(&mut self, rhs: &DVec<T>) {
+        assert_eq!(
+            self.data.len(),
+            rhs.data.len(),
+            "DVec length mismatch in sub_assign"
+        );
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a = *a - *b;
+        }
+    }
+}
+
 impl<T> DVec<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    T: DVecOps,
 {
     pub fn new(data: Vec<T>) -> Self {
         assert!(!data.is_empty(), "DVec cannot be empty");
@@ -161,18 +449,42 @@ where
             return Err(VectorError::DimensionMismatch);
         }
 
-        Ok(self
-            .data
-            .iter()
-            .zip(&other.data)
-            .map(|(a, b)| *a * *b)
-            .fold(self.data[0] - self.data[0], |acc, val| acc + val))
+        Ok(T::dot(&self.data, &other.data))
+    }
+}
+
+impl<T> DVec<T>
+where
+    T: DVecOps + Float,
+{
+    pub fn norm_squared(&self) -> T {
+        T::dot(&self.data, &self.data)
+    }
+
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> Result<Self, VectorError> {
+        let n = self.norm();
+        if n == T::zero() {
+            return Err(VectorError::ZeroNorm);
+        }
+
+        Ok(Self {
+            data: self.data.iter().map(|x| *x / n).collect(),
+        })
+    }
+
+    pub fn distance(&self, other: &Self) -> Result<T, VectorError> {
+        let diff = (self - other)?;
+        Ok(diff.norm())
     }
 }
 
 impl<T> Add for DVec<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    T: DVecOps,
 {
     type Output = Result<DVec<T>, VectorError>;
 
@@ -183,7 +495,7 @@ where
 
 impl<T> Add for &DVec<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    T: DVecOps,
 {
     type Output = Result<DVec<T>, VectorError>;
 
@@ -191,12 +503,8 @@ where
         if self.len() != rhs.len() {
             return Err(VectorError::DimensionMismatch);
         }
-        let result_data: Vec<T> = self
-            .data
-            .iter()
-            .zip(&rhs.data)
-            .map(|(a, b)| *a + *b)
-            .collect();
+        let mut result_data = Vec::with_capacity(self.len());
+        T::add_into(&self.data, &rhs.data, &mut result_data);
 
         Ok(DVec { data: result_data })
     }
@@ -204,7 +512,7 @@ where
 
 impl<T> Sub for DVec<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    T: DVecOps,
 {
     type Output = Result<DVec<T>, VectorError>;
 
@@ -215,7 +523,7 @@ where
 
 impl<T> Sub for &DVec<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    T: DVecOps,
 {
     type Output = Result<DVec<T>, VectorError>;
 
@@ -223,12 +531,8 @@ where
         if self.len() != rhs.len() {
             return Err(VectorError::DimensionMismatch);
         }
-        let result_data: Vec<T> = self
-            .data
-            .iter()
-            .zip(&rhs.data)
-            .map(|(a, b)| *a - *b)
-            .collect();
+        let mut result_data = Vec::with_capacity(self.len());
+        T::sub_into(&self.data, &rhs.data, &mut result_data);
 
         Ok(DVec { data: result_data })
     }
@@ -296,4 +600,144 @@ mod tests {
 
         assert_eq!((&x + &y).unwrap_err(), VectorError::DimensionMismatch);
     }
+
+    // `LANES` for f32 is 8 and for f64 is 4, so these lengths are chosen to
+    // straddle a chunk boundary and exercise the scalar tail loop.
+    #[test]
+    fn test_dvec_simd_tail_f32() {
+        for len in [1usize, 7, 8, 9, 17, 31] {
+            let a: Vec<f32> = (0..len).map(|i| i as f32).collect();
+            let b: Vec<f32> = (0..len).map(|i| (i * 2) as f32).collect();
+            let x = DVec::new(a.clone());
+            let y = DVec::new(b.clone());
+
+            let expected_dot: f32 = a.iter().zip(&b).map(|(p, q)| p * q).sum();
+            assert_eq!(x.dot(&y).unwrap(), expected_dot);
+
+            let expected_sum: Vec<f32> = a.iter().zip(&b).map(|(p, q)| p + q).collect();
+            assert_eq!((&x + &y).unwrap(), DVec::new(expected_sum));
+
+            let expected_diff: Vec<f32> = a.iter().zip(&b).map(|(p, q)| p - q).collect();
+            assert_eq!((&x - &y).unwrap(), DVec::new(expected_diff));
+        }
+    }
+
+    #[test]
+    fn test_dvec_simd_tail_f64() {
+        for len in [1usize, 3, 4, 5, 13, 29] {
+            let a: Vec<f64> = (0..len).map(|i| i as f64).collect();
+            let b: Vec<f64> = (0..len).map(|i| (i * 2) as f64).collect();
+            let x = DVec::new(a.clone());
+            let y = DVec::new(b.clone());
+
+            let expected_dot: f64 = a.iter().zip(&b).map(|(p, q)| p * q).sum();
+            assert_eq!(x.dot(&y).unwrap(), expected_dot);
+
+            let expected_sum: Vec<f64> = a.iter().zip(&b).map(|(p, q)| p + q).collect();
+            assert_eq!((&x + &y).unwrap(), DVec::new(expected_sum));
+
+            let expected_diff: Vec<f64> = a.iter().zip(&b).map(|(p, q)| p - q).collect();
+            assert_eq!((&x - &y).unwrap(), DVec::new(expected_diff));
+        }
+    }
+
+    #[test]
+    fn test_dvec_integer_ops_still_work() {
+        let x = DVec::new(vec![1i32, 2, 3, 4, 5]);
+        let y = DVec::new(vec![5i32, 4, 3, 2, 1]);
+
+        assert_eq!(x.dot(&y).unwrap(), 5 + 8 + 9 + 8 + 5);
+        assert_eq!((&x + &y).unwrap(), DVec::new(vec![6, 6, 6, 6, 6]));
+    }
+
+    #[test]
+    fn test_dvec_narrow_int_types_still_work() {
+        let x = DVec::new(vec![1i8, 2, 3]);
+        let y = DVec::new(vec![3i8, 2, 1]);
+
+        assert_eq!(x.dot(&y).unwrap(), 3 + 4 + 3);
+        assert_eq!((&x + &y).unwrap(), DVec::new(vec![4i8, 4, 4]));
+    }
+
+    #[test]
+    fn test_vec2_norm_normalize_distance() {
+        let x = Vec2::new(3.0, 4.0);
+        assert_eq!(x.norm_squared(), 25.0);
+        assert_eq!(x.norm(), 5.0);
+        assert_eq!(x.normalize(), Vec2::new(0.6, 0.8));
+
+        let y = Vec2::new(0.0, 0.0);
+        assert_eq!(x.distance(y), 5.0);
+    }
+
+    #[test]
+    fn test_vec3_norm_normalize_distance_cross() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(x.cross(y), Vec3::new(0.0, 0.0, 1.0));
+
+        let z = Vec3::new(0.0, 3.0, 4.0);
+        assert_eq!(z.norm(), 5.0);
+        assert_eq!(z.normalize(), Vec3::new(0.0, 0.6, 0.8));
+        assert_eq!(x.distance(Vec3::new(0.0, 0.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn test_dvec_norm_normalize_distance() {
+        let x = DVec::new(vec![3.0, 4.0]);
+        assert_eq!(x.norm_squared(), 25.0);
+        assert_eq!(x.norm(), 5.0);
+        assert_eq!(x.normalize().unwrap(), DVec::new(vec![0.6, 0.8]));
+
+        let origin = DVec::new(vec![0.0, 0.0]);
+        assert_eq!(x.distance(&origin).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_dvec_normalize_zero_vector() {
+        let x = DVec::new(vec![0.0, 0.0]);
+        assert_eq!(x.normalize().unwrap_err(), VectorError::ZeroNorm);
+    }
+
+    #[test]
+    fn test_dvec_from_iterator() {
+        let x: DVec<f64> = (1..=4).map(|i| i as f64).collect();
+        assert_eq!(x, DVec::new(vec![1.0, 2.0, 3.0, 4.0]));
+    }
+
+    #[test]
+    fn test_dvec_indexing() {
+        let mut x = DVec::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(x[1], 2.0);
+
+        x[1] = 5.0;
+        assert_eq!(x, DVec::new(vec![1.0, 5.0, 3.0]));
+    }
+
+    #[test]
+    fn test_dvec_iter_and_slices() {
+        let mut x = DVec::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(x.iter().sum::<f64>(), 6.0);
+
+        for v in x.iter_mut() {
+            *v *= 2.0;
+        }
+        assert_eq!(x.as_slice(), &[2.0, 4.0, 6.0]);
+
+        x.as_mut_slice()[0] = 10.0;
+        assert_eq!(x, DVec::new(vec![10.0, 4.0, 6.0]));
+    }
+
+    #[test]
+    fn test_dvec_add_assign_sub_assign() {
+        let mut x = DVec::new(vec![1.0, 2.0, 3.0]);
+        let y = DVec::new(vec![3.0, 2.0, 1.0]);
+
+        x += &y;
+        assert_eq!(x, DVec::new(vec![4.0, 4.0, 4.0]));
+
+        x -= &y;
+        assert_eq!(x, DVec::new(vec![1.0, 2.0, 3.0]));
+    }
 }